@@ -33,12 +33,14 @@
 //! Both the lexing and parsing stages return `Result` types, allowing for error handling.
 //! Common errors include invalid commit type, missing description, and unclosed scope parentheses.
 
-/// Represents the different components of a conventional commit message.
+use std::collections::{BTreeMap, HashMap};
 
 #[cfg(test)]
 mod tests;
 
+/// Represents the different components of a conventional commit message.
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Token {
     /// The type of the commit, e.g., "feat", "fix", "docs", etc.
     CommitType(String),
@@ -50,12 +52,36 @@ pub enum Token {
     Description(String),
     /// The body of the commit message, providing additional contextual information.
     Body(String),
-    /// The footer of the commit message, often used for referencing issues.
+    /// The raw, unsplit footer section of the commit message, e.g. `"Reviewed-by: Alice"`.
     Footer(String),
 }
 
+/// The separator between a footer's token and its value, per the Conventional Commits spec.
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FooterSeparator {
+    /// A `": "` separator, e.g. `Reviewed-by: Alice`.
+    ColonSpace,
+    /// A `" #"` separator, e.g. `Fixes #42`.
+    HashSpace,
+}
+
+/// A single footer (git trailer) parsed from the footer section of a commit message,
+/// e.g. `Reviewed-by: Alice` or `Fixes #42`.
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Footer {
+    /// The footer token, e.g. `Reviewed-by`, `BREAKING CHANGE`, `Fixes`.
+    pub token: String,
+    /// The separator used between the token and the value.
+    pub separator: FooterSeparator,
+    /// The footer's value, which may span multiple lines.
+    pub value: String,
+}
+
 /// Represents a parsed conventional commit.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConventionalCommit {
     /// The type of the commit, e.g., "feat", "fix", "docs", etc.
     pub commit_type: String,
@@ -67,8 +93,28 @@ pub struct ConventionalCommit {
     pub description: String,
     /// The body of the commit message, providing additional contextual information.
     pub body: Option<String>,
-    /// The footer of the commit message, often used for referencing issues.
-    pub footer: Option<String>,
+    /// The footers (git trailers) of the commit message, e.g. `Reviewed-by`, `Fixes`, `BREAKING CHANGE`.
+    pub footers: Vec<Footer>,
+    /// The human-readable breaking-change description, if this commit is breaking via a
+    /// `BREAKING CHANGE:` / `BREAKING-CHANGE:` footer. `None` when the commit is not
+    /// breaking, or when it is breaking only via the `!` marker.
+    ///
+    /// Skipped when serializing: it only duplicates the value already present in `footers`.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub breaking_description: Option<String>,
+}
+
+/// The semantic-version bump a commit implies, per Conventional Commits' versioning rules.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum VersionIncrement {
+    /// A breaking change, bumping the major version.
+    Major,
+    /// A new backwards-compatible feature, bumping the minor version.
+    Minor,
+    /// A backwards-compatible bug fix, bumping the patch version.
+    Patch,
+    /// A commit that implies no version change.
+    None,
 }
 
 impl ConventionalCommit {
@@ -81,14 +127,16 @@ impl ConventionalCommit {
     /// * `breaking_change` - Whether the commit introduces a breaking change.
     /// * `description` - A short description of the commit.
     /// * `body` - An optional body providing more context.
-    /// * `footer` - An optional footer, often used for issue references.
+    /// * `footers` - The footers (git trailers) parsed from the commit message.
+    /// * `breaking_description` - The breaking-change description derived from the footers.
     fn new(
         commit_type: String,
         scope: Option<String>,
         breaking_change: bool,
         description: String,
         body: Option<String>,
-        footer: Option<String>,
+        footers: Vec<Footer>,
+        breaking_description: Option<String>,
     ) -> Self {
         ConventionalCommit {
             commit_type,
@@ -96,12 +144,120 @@ impl ConventionalCommit {
             breaking_change,
             description,
             body,
-            footer,
+            footers,
+            breaking_description,
+        }
+    }
+
+    /// Determines the semantic-version bump implied by this commit.
+    ///
+    /// A breaking change always yields `Major`. Otherwise, `overrides` is consulted first for
+    /// a per-commit-type mapping (e.g. `perf => Patch`); when no override matches, `feat`
+    /// yields `Minor`, `fix` yields `Patch`, and every other commit type yields `None`.
+    ///
+    /// # Arguments
+    ///
+    /// * `overrides` - An optional mapping from commit type to the version increment it implies.
+    pub fn version_increment(
+        &self,
+        overrides: Option<&HashMap<String, VersionIncrement>>,
+    ) -> VersionIncrement {
+        if self.breaking_change {
+            return VersionIncrement::Major;
+        }
+
+        if let Some(increment) = overrides.and_then(|o| o.get(&self.commit_type)) {
+            return *increment;
+        }
+
+        match self.commit_type.as_str() {
+            "feat" => VersionIncrement::Minor,
+            "fix" => VersionIncrement::Patch,
+            _ => VersionIncrement::None,
+        }
+    }
+
+    /// Validates this commit against project policy, collecting every violation found rather
+    /// than failing on the first.
+    ///
+    /// # Arguments
+    ///
+    /// * `cfg` - The policy to validate against.
+    pub fn validate(&self, cfg: &CommitConfig) -> Result<(), Vec<LintError>> {
+        let mut errors: Vec<LintError> = Vec::new();
+
+        if !cfg.allowed_types.iter().any(|t| t == &self.commit_type) {
+            errors.push(LintError::DisallowedType(self.commit_type.clone()));
+        }
+
+        if cfg.require_scope && self.scope.is_none() {
+            errors.push(LintError::MissingScope);
+        }
+
+        if let Some(max) = cfg.max_description_len {
+            let actual: usize = self.description.chars().count();
+            if actual > max {
+                errors.push(LintError::DescriptionTooLong { max, actual });
+            }
+        }
+
+        if self
+            .description
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_uppercase())
+        {
+            errors.push(LintError::DescriptionStartsUppercase);
+        }
+
+        if self.description.ends_with('.') {
+            errors.push(LintError::DescriptionEndsWithPeriod);
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
         }
     }
 }
 
+/// Project policy used by `ConventionalCommit::validate` to lint parsed commits.
+#[derive(Debug)]
+pub struct CommitConfig {
+    /// The commit types this project allows, e.g. `feat`, `fix`, `docs`, `chore`.
+    pub allowed_types: Vec<String>,
+    /// Whether every commit must declare a scope.
+    pub require_scope: bool,
+    /// The maximum allowed length of the description, in characters.
+    pub max_description_len: Option<usize>,
+}
+
+/// A single policy violation found by `ConventionalCommit::validate`.
+#[derive(Debug, PartialEq)]
+pub enum LintError {
+    /// The commit type is not in `CommitConfig::allowed_types`.
+    DisallowedType(String),
+    /// `CommitConfig::require_scope` is set but the commit has no scope.
+    MissingScope,
+    /// The description is longer than `CommitConfig::max_description_len` characters.
+    DescriptionTooLong {
+        /// The configured maximum length.
+        max: usize,
+        /// The description's actual length.
+        actual: usize,
+    },
+    /// The description starts with an uppercase letter.
+    DescriptionStartsUppercase,
+    /// The description ends with a period.
+    DescriptionEndsWithPeriod,
+}
+
 /// A lexer for tokenizing conventional commit messages.
+///
+/// `position` is a byte offset into `input`, always kept on a UTF-8 character boundary so
+/// that it can be used directly for slicing (as `lex_body_and_footer` does) without panicking
+/// on multi-byte characters.
 pub struct Lexer {
     input: String,
     position: usize,
@@ -118,19 +274,19 @@ impl Lexer {
     }
 
     /// Returns the next character in the input without consuming it.
+    ///
+    /// Decodes a single character from the current byte offset rather than re-walking the
+    /// input from the start, keeping lexing linear in the length of the message.
     fn peek(&self) -> Option<char> {
-        self.input.chars().nth(self.position)
+        self.input[self.position..].chars().next()
     }
 
-    /// Consumes and returns the next character in the input.
+    /// Consumes and returns the next character in the input, advancing `position` by its
+    /// UTF-8 byte length.
     fn next(&mut self) -> Option<char> {
-        if self.position < self.input.len() {
-            let c: char = self.input.chars().nth(self.position)?;
-            self.position += 1;
-            Some(c)
-        } else {
-            None
-        }
+        let c: char = self.peek()?;
+        self.position += c.len_utf8();
+        Some(c)
     }
 
     /// Skips any whitespace characters.
@@ -233,6 +389,11 @@ impl Lexer {
 
     /// Tokenizes the body and footer of the commit message.
     ///
+    /// The footer section is detected by scanning for the first line that looks like a
+    /// git trailer (a `-`/alphanumeric token, or the literal `BREAKING CHANGE`, followed by
+    /// `": "` or `" #"`); everything before it is the body, everything from it onward is the
+    /// footer block, which is later split into individual `Footer`s by `parse_footers`.
+    ///
     /// # Returns
     ///
     /// A tuple containing optional `Body` and `Footer` tokens.
@@ -240,13 +401,20 @@ impl Lexer {
         self.skip_whitespace();
 
         let remaining_input: &str = &self.input[self.position..];
+        self.position += remaining_input.len();
 
-        if let Some(index) = remaining_input
-            .find("BREAKING CHANGE:")
-            .or_else(|| remaining_input.find("Reviewed-by:"))
-            .or_else(|| remaining_input.find("Refs:"))
-        {
-            let (body_part, footer_part) = remaining_input.split_at(index);
+        if remaining_input.is_empty() {
+            return (None, None);
+        }
+
+        let lines: Vec<&str> = remaining_input.split('\n').collect();
+        let footer_start: Option<usize> = lines
+            .iter()
+            .position(|line| parse_footer_token_line(line).is_some());
+
+        if let Some(index) = footer_start {
+            let body_part: String = lines[..index].join("\n");
+            let footer_part: String = lines[index..].join("\n");
 
             let body: Option<Token> = if !body_part.trim().is_empty() {
                 Some(Token::Body(body_part.trim().to_string()))
@@ -259,13 +427,7 @@ impl Lexer {
             return (body, footer);
         }
 
-        if !remaining_input.is_empty() {
-            let body: Option<Token> = Some(Token::Body(remaining_input.trim().to_string()));
-            self.position += remaining_input.len();
-            return (body, None);
-        }
-
-        (None, None)
+        (Some(Token::Body(remaining_input.trim().to_string())), None)
     }
 
     /// Tokenizes the entire input commit message.
@@ -308,6 +470,74 @@ impl Lexer {
     }
 }
 
+/// Checks whether `line` begins a new footer, returning its token, separator and the
+/// value found on this line.
+///
+/// A footer token is either the literal `BREAKING CHANGE` or a run of `-`/alphanumeric
+/// characters, followed immediately by `": "` or `" #"`.
+fn parse_footer_token_line(line: &str) -> Option<(String, FooterSeparator, String)> {
+    if let Some(value) = line.strip_prefix("BREAKING CHANGE: ") {
+        return Some((
+            "BREAKING CHANGE".to_string(),
+            FooterSeparator::ColonSpace,
+            value.to_string(),
+        ));
+    }
+    if let Some(value) = line.strip_prefix("BREAKING CHANGE #") {
+        return Some((
+            "BREAKING CHANGE".to_string(),
+            FooterSeparator::HashSpace,
+            value.to_string(),
+        ));
+    }
+
+    let token_len: usize = line
+        .char_indices()
+        .take_while(|(_, c)| c.is_alphanumeric() || *c == '-')
+        .map(|(i, c)| i + c.len_utf8())
+        .last()
+        .unwrap_or(0);
+
+    if token_len == 0 {
+        return None;
+    }
+
+    let token: &str = &line[..token_len];
+    let rest: &str = &line[token_len..];
+
+    if let Some(value) = rest.strip_prefix(": ") {
+        return Some((token.to_string(), FooterSeparator::ColonSpace, value.to_string()));
+    }
+    if let Some(value) = rest.strip_prefix(" #") {
+        return Some((token.to_string(), FooterSeparator::HashSpace, value.to_string()));
+    }
+
+    None
+}
+
+/// Splits a raw footer block (as produced by `Lexer::lex_body_and_footer`) into individual
+/// `Footer`s, joining continuation lines into the value of the footer they follow.
+fn parse_footers(raw: &str) -> Vec<Footer> {
+    let mut footers: Vec<Footer> = Vec::new();
+
+    for line in raw.lines() {
+        if let Some((token, separator, value)) = parse_footer_token_line(line) {
+            footers.push(Footer {
+                token,
+                separator,
+                value,
+            });
+        } else if let Some(last) = footers.last_mut() {
+            if !last.value.is_empty() {
+                last.value.push('\n');
+            }
+            last.value.push_str(line);
+        }
+    }
+
+    footers
+}
+
 /// Parses a vector of `Token`s into a `ConventionalCommit` struct.
 ///
 /// # Arguments
@@ -323,7 +553,7 @@ pub fn parse_commit(tokens: Vec<Token>) -> Result<ConventionalCommit, String> {
     let mut breaking_change: bool = false;
     let mut description: Option<String> = None;
     let mut body: Option<String> = None;
-    let mut footer: Option<String> = None;
+    let mut footer_raw: Option<String> = None;
 
     for token in tokens {
         match token {
@@ -332,10 +562,20 @@ pub fn parse_commit(tokens: Vec<Token>) -> Result<ConventionalCommit, String> {
             Token::BreakingChangeMarker => breaking_change = true,
             Token::Description(d) => description = Some(d),
             Token::Body(b) => body = Some(b),
-            Token::Footer(f) => footer = Some(f),
+            Token::Footer(f) => footer_raw = Some(f),
         }
     }
 
+    let footers: Vec<Footer> = footer_raw.map(|f| parse_footers(&f)).unwrap_or_default();
+
+    let breaking_footer: Option<&Footer> = footers
+        .iter()
+        .find(|f| f.token == "BREAKING CHANGE" || f.token == "BREAKING-CHANGE");
+    if breaking_footer.is_some() {
+        breaking_change = true;
+    }
+    let breaking_description: Option<String> = breaking_footer.map(|f| f.value.clone());
+
     if let Some(commit_type) = commit_type {
         if let Some(description) = description {
             Ok(ConventionalCommit::new(
@@ -344,7 +584,8 @@ pub fn parse_commit(tokens: Vec<Token>) -> Result<ConventionalCommit, String> {
                 breaking_change,
                 description,
                 body,
-                footer,
+                footers,
+                breaking_description,
             ))
         } else {
             Err("Missing description".to_string())
@@ -353,3 +594,63 @@ pub fn parse_commit(tokens: Vec<Token>) -> Result<ConventionalCommit, String> {
         Err("Missing commit type".to_string())
     }
 }
+
+/// Groups commits by the changelog section they belong to.
+///
+/// Commits are keyed by `commit_type`, except breaking-change commits, which are grouped
+/// into a `"Breaking Changes"` section of their own regardless of their type.
+pub fn group_by_type(commits: &[ConventionalCommit]) -> BTreeMap<String, Vec<&ConventionalCommit>> {
+    let mut groups: BTreeMap<String, Vec<&ConventionalCommit>> = BTreeMap::new();
+
+    for commit in commits {
+        let key: String = if commit.breaking_change {
+            "Breaking Changes".to_string()
+        } else {
+            commit.commit_type.clone()
+        };
+        groups.entry(key).or_default().push(commit);
+    }
+
+    groups
+}
+
+/// The section headings this crate uses out of the box, mapping a commit type to the
+/// heading its changelog section renders under.
+pub fn default_section_headings() -> HashMap<String, String> {
+    let mut headings: HashMap<String, String> = HashMap::new();
+    headings.insert("feat".to_string(), "Features".to_string());
+    headings.insert("fix".to_string(), "Bug Fixes".to_string());
+    headings
+}
+
+/// Renders `commits`, grouped by `group_by_type`, as a markdown changelog.
+///
+/// `headings` maps a group key (a commit type, or `"Breaking Changes"`) to the section
+/// heading to render; a group with no mapped heading falls back to its raw key.
+///
+/// # Arguments
+///
+/// * `commits` - The commits to render.
+/// * `headings` - The mapping from group key to section heading.
+pub fn render_markdown(commits: &[ConventionalCommit], headings: &HashMap<String, String>) -> String {
+    let groups: BTreeMap<String, Vec<&ConventionalCommit>> = group_by_type(commits);
+    let mut output: String = String::new();
+
+    for (key, commits) in &groups {
+        let heading: &str = headings.get(key).map(|h| h.as_str()).unwrap_or(key);
+        output.push_str("## ");
+        output.push_str(heading);
+        output.push_str("\n\n");
+
+        for commit in commits {
+            match &commit.scope {
+                Some(scope) => output.push_str(&format!("- **{}:** {}\n", scope, commit.description)),
+                None => output.push_str(&format!("- {}\n", commit.description)),
+            }
+        }
+
+        output.push('\n');
+    }
+
+    output.trim_end().to_string()
+}
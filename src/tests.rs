@@ -12,7 +12,7 @@ fn test_basic_commit() {
     assert!(!commit.breaking_change);
     assert_eq!(commit.description, "add a new feature");
     assert_eq!(commit.body, None);
-    assert_eq!(commit.footer, None);
+    assert!(commit.footers.is_empty());
 }
 
 #[test]
@@ -27,7 +27,7 @@ fn test_commit_with_scope() {
     assert!(!commit.breaking_change);
     assert_eq!(commit.description, "fix a bug in the parser");
     assert_eq!(commit.body, None);
-    assert_eq!(commit.footer, None);
+    assert!(commit.footers.is_empty());
 }
 
 #[test]
@@ -42,7 +42,7 @@ fn test_commit_with_breaking_change() {
     assert!(commit.breaking_change);
     assert_eq!(commit.description, "add a new feature that breaks API");
     assert_eq!(commit.body, None);
-    assert_eq!(commit.footer, None);
+    assert!(commit.footers.is_empty());
 }
 
 #[test]
@@ -57,7 +57,7 @@ fn test_commit_with_scope_and_breaking_change() {
     assert!(commit.breaking_change);
     assert_eq!(commit.description, "refactor core functionality");
     assert_eq!(commit.body, None);
-    assert_eq!(commit.footer, None);
+    assert!(commit.footers.is_empty());
 }
 
 #[test]
@@ -76,7 +76,7 @@ fn test_commit_with_body() {
         commit.body,
         Some("This feature allows parsing of commits.".to_string())
     );
-    assert_eq!(commit.footer, None);
+    assert!(commit.footers.is_empty());
 }
 
 #[test]
@@ -91,7 +91,62 @@ fn test_commit_with_footer() {
     assert!(!commit.breaking_change);
     assert_eq!(commit.description, "add a new feature");
     assert_eq!(commit.body, None);
-    assert_eq!(commit.footer, Some("Reviewed-by: Alice".to_string()));
+    assert_eq!(
+        commit.footers,
+        vec![Footer {
+            token: "Reviewed-by".to_string(),
+            separator: FooterSeparator::ColonSpace,
+            value: "Alice".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_commit_with_multiple_footers() {
+    let input: String =
+        "fix: patch a bug\n\nFixes #42\nCo-authored-by: Bob\nSigned-off-by: Alice".to_string();
+    let mut lexer: Lexer = Lexer::new(input);
+    let tokens: Vec<Token> = lexer.lex().unwrap();
+    let commit: ConventionalCommit = parse_commit(tokens).unwrap();
+
+    assert_eq!(
+        commit.footers,
+        vec![
+            Footer {
+                token: "Fixes".to_string(),
+                separator: FooterSeparator::HashSpace,
+                value: "42".to_string(),
+            },
+            Footer {
+                token: "Co-authored-by".to_string(),
+                separator: FooterSeparator::ColonSpace,
+                value: "Bob".to_string(),
+            },
+            Footer {
+                token: "Signed-off-by".to_string(),
+                separator: FooterSeparator::ColonSpace,
+                value: "Alice".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_commit_with_multiline_footer_value() {
+    let input: String =
+        "fix: patch a bug\n\nReviewed-by: Alice\nLooks good to me.".to_string();
+    let mut lexer: Lexer = Lexer::new(input);
+    let tokens: Vec<Token> = lexer.lex().unwrap();
+    let commit: ConventionalCommit = parse_commit(tokens).unwrap();
+
+    assert_eq!(
+        commit.footers,
+        vec![Footer {
+            token: "Reviewed-by".to_string(),
+            separator: FooterSeparator::ColonSpace,
+            value: "Alice\nLooks good to me.".to_string(),
+        }]
+    );
 }
 
 #[test]
@@ -111,7 +166,55 @@ fn test_commit_with_body_and_footer() {
         commit.body,
         Some("This feature allows parsing of commits.".to_string())
     );
-    assert_eq!(commit.footer, Some("Reviewed-by: Alice".to_string()));
+    assert_eq!(
+        commit.footers,
+        vec![Footer {
+            token: "Reviewed-by".to_string(),
+            separator: FooterSeparator::ColonSpace,
+            value: "Alice".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_breaking_change_from_footer() {
+    let input: String =
+        "feat: add a new feature\n\nBREAKING CHANGE: the API surface has changed".to_string();
+    let mut lexer: Lexer = Lexer::new(input);
+    let tokens: Vec<Token> = lexer.lex().unwrap();
+    let commit: ConventionalCommit = parse_commit(tokens).unwrap();
+
+    assert!(commit.breaking_change);
+    assert_eq!(
+        commit.breaking_description,
+        Some("the API surface has changed".to_string())
+    );
+}
+
+#[test]
+fn test_breaking_change_from_hyphenated_footer() {
+    let input: String =
+        "feat: add a new feature\n\nBREAKING-CHANGE: the API surface has changed".to_string();
+    let mut lexer: Lexer = Lexer::new(input);
+    let tokens: Vec<Token> = lexer.lex().unwrap();
+    let commit: ConventionalCommit = parse_commit(tokens).unwrap();
+
+    assert!(commit.breaking_change);
+    assert_eq!(
+        commit.breaking_description,
+        Some("the API surface has changed".to_string())
+    );
+}
+
+#[test]
+fn test_breaking_change_marker_without_footer_has_no_description() {
+    let input: String = "feat!: add a new feature".to_string();
+    let mut lexer: Lexer = Lexer::new(input);
+    let tokens: Vec<Token> = lexer.lex().unwrap();
+    let commit: ConventionalCommit = parse_commit(tokens).unwrap();
+
+    assert!(commit.breaking_change);
+    assert_eq!(commit.breaking_description, None);
 }
 
 #[test]
@@ -149,3 +252,204 @@ fn test_invalid_commit_type() {
 
     assert!(tokens.is_err());
 }
+
+#[test]
+fn test_version_increment_breaking_change_is_major() {
+    let input: String = "feat!: add a new feature".to_string();
+    let mut lexer: Lexer = Lexer::new(input);
+    let commit: ConventionalCommit = parse_commit(lexer.lex().unwrap()).unwrap();
+
+    assert_eq!(commit.version_increment(None), VersionIncrement::Major);
+}
+
+#[test]
+fn test_version_increment_feat_is_minor() {
+    let input: String = "feat: add a new feature".to_string();
+    let mut lexer: Lexer = Lexer::new(input);
+    let commit: ConventionalCommit = parse_commit(lexer.lex().unwrap()).unwrap();
+
+    assert_eq!(commit.version_increment(None), VersionIncrement::Minor);
+}
+
+#[test]
+fn test_version_increment_fix_is_patch() {
+    let input: String = "fix: fix a bug".to_string();
+    let mut lexer: Lexer = Lexer::new(input);
+    let commit: ConventionalCommit = parse_commit(lexer.lex().unwrap()).unwrap();
+
+    assert_eq!(commit.version_increment(None), VersionIncrement::Patch);
+}
+
+#[test]
+fn test_version_increment_other_type_is_none() {
+    let input: String = "chore: update dependencies".to_string();
+    let mut lexer: Lexer = Lexer::new(input);
+    let commit: ConventionalCommit = parse_commit(lexer.lex().unwrap()).unwrap();
+
+    assert_eq!(commit.version_increment(None), VersionIncrement::None);
+}
+
+#[test]
+fn test_version_increment_with_override() {
+    let input: String = "perf: speed up the parser".to_string();
+    let mut lexer: Lexer = Lexer::new(input);
+    let commit: ConventionalCommit = parse_commit(lexer.lex().unwrap()).unwrap();
+
+    let mut overrides: HashMap<String, VersionIncrement> = HashMap::new();
+    overrides.insert("perf".to_string(), VersionIncrement::Patch);
+
+    assert_eq!(commit.version_increment(None), VersionIncrement::None);
+    assert_eq!(
+        commit.version_increment(Some(&overrides)),
+        VersionIncrement::Patch
+    );
+}
+
+#[test]
+fn test_version_increment_breaking_overrides_type_mapping() {
+    let input: String = "fix!: fix a bug that breaks API".to_string();
+    let mut lexer: Lexer = Lexer::new(input);
+    let commit: ConventionalCommit = parse_commit(lexer.lex().unwrap()).unwrap();
+
+    let mut overrides: HashMap<String, VersionIncrement> = HashMap::new();
+    overrides.insert("fix".to_string(), VersionIncrement::Patch);
+
+    assert_eq!(
+        commit.version_increment(Some(&overrides)),
+        VersionIncrement::Major
+    );
+}
+
+fn default_commit_config() -> CommitConfig {
+    CommitConfig {
+        allowed_types: vec!["feat".to_string(), "fix".to_string(), "chore".to_string()],
+        require_scope: false,
+        max_description_len: None,
+    }
+}
+
+#[test]
+fn test_validate_passes_for_compliant_commit() {
+    let input: String = "feat: add a new feature".to_string();
+    let mut lexer: Lexer = Lexer::new(input);
+    let commit: ConventionalCommit = parse_commit(lexer.lex().unwrap()).unwrap();
+
+    assert_eq!(commit.validate(&default_commit_config()), Ok(()));
+}
+
+#[test]
+fn test_validate_rejects_disallowed_type() {
+    let input: String = "invalid: add a new feature".to_string();
+    let mut lexer: Lexer = Lexer::new(input);
+    let commit: ConventionalCommit = parse_commit(lexer.lex().unwrap()).unwrap();
+
+    assert_eq!(
+        commit.validate(&default_commit_config()),
+        Err(vec![LintError::DisallowedType("invalid".to_string())])
+    );
+}
+
+#[test]
+fn test_validate_requires_scope() {
+    let input: String = "feat: add a new feature".to_string();
+    let mut lexer: Lexer = Lexer::new(input);
+    let commit: ConventionalCommit = parse_commit(lexer.lex().unwrap()).unwrap();
+
+    let cfg: CommitConfig = CommitConfig {
+        require_scope: true,
+        ..default_commit_config()
+    };
+
+    assert_eq!(commit.validate(&cfg), Err(vec![LintError::MissingScope]));
+}
+
+#[test]
+fn test_validate_rejects_overlong_description() {
+    let input: String = "feat: add a new feature that is too long".to_string();
+    let mut lexer: Lexer = Lexer::new(input);
+    let commit: ConventionalCommit = parse_commit(lexer.lex().unwrap()).unwrap();
+
+    let cfg: CommitConfig = CommitConfig {
+        max_description_len: Some(10),
+        ..default_commit_config()
+    };
+
+    assert_eq!(
+        commit.validate(&cfg),
+        Err(vec![LintError::DescriptionTooLong {
+            max: 10,
+            actual: commit.description.chars().count(),
+        }])
+    );
+}
+
+#[test]
+fn test_validate_rejects_uppercase_and_trailing_period() {
+    let input: String = "feat: Add a new feature.".to_string();
+    let mut lexer: Lexer = Lexer::new(input);
+    let commit: ConventionalCommit = parse_commit(lexer.lex().unwrap()).unwrap();
+
+    assert_eq!(
+        commit.validate(&default_commit_config()),
+        Err(vec![
+            LintError::DescriptionStartsUppercase,
+            LintError::DescriptionEndsWithPeriod,
+        ])
+    );
+}
+
+fn parse(input: &str) -> ConventionalCommit {
+    let mut lexer: Lexer = Lexer::new(input.to_string());
+    parse_commit(lexer.lex().unwrap()).unwrap()
+}
+
+#[test]
+fn test_commit_with_multibyte_scope_and_body_does_not_panic() {
+    let input: String =
+        "feat(パーサー): 新しい機能を追加\n\n本文です。\n\nReviewed-by: Alice".to_string();
+    let mut lexer: Lexer = Lexer::new(input);
+    let tokens: Vec<Token> = lexer.lex().unwrap();
+    let commit: ConventionalCommit = parse_commit(tokens).unwrap();
+
+    assert_eq!(commit.scope, Some("パーサー".to_string()));
+    assert_eq!(commit.description, "新しい機能を追加");
+    assert_eq!(commit.body, Some("本文です。".to_string()));
+    assert_eq!(
+        commit.footers,
+        vec![Footer {
+            token: "Reviewed-by".to_string(),
+            separator: FooterSeparator::ColonSpace,
+            value: "Alice".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_group_by_type_separates_breaking_changes() {
+    let commits: Vec<ConventionalCommit> = vec![
+        parse("feat: add a new feature"),
+        parse("fix: fix a bug"),
+        parse("feat!: change the API"),
+    ];
+
+    let groups: BTreeMap<String, Vec<&ConventionalCommit>> = group_by_type(&commits);
+
+    assert_eq!(groups.get("feat").unwrap().len(), 1);
+    assert_eq!(groups.get("fix").unwrap().len(), 1);
+    assert_eq!(groups.get("Breaking Changes").unwrap().len(), 1);
+}
+
+#[test]
+fn test_render_markdown_groups_and_formats_entries() {
+    let commits: Vec<ConventionalCommit> = vec![
+        parse("feat(parser): add ability to parse conventional commits"),
+        parse("fix: fix a bug in the parser"),
+    ];
+
+    let rendered: String = render_markdown(&commits, &default_section_headings());
+
+    assert_eq!(
+        rendered,
+        "## Features\n\n- **parser:** add ability to parse conventional commits\n\n## Bug Fixes\n\n- fix a bug in the parser"
+    );
+}